@@ -3,9 +3,190 @@ use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use proc_macro_error::*;
 use quote::quote;
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Eq;
-use syn::ItemEnum;
+use syn::{ItemEnum, Token};
+
+/// The diesel backends a `#[derive(FromToSql)]` enum can target.
+///
+/// Defaults to [`Backend::Postgres`] when no `backend(...)` argument is given, which
+/// matches the original Postgres-only behaviour of this crate.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl Backend {
+    fn parse(ident: &Ident) -> Backend {
+        match ident.to_string().as_str() {
+            "postgres" => Backend::Postgres,
+            "mysql" => Backend::Mysql,
+            "sqlite" => Backend::Sqlite,
+            other => abort!(
+                ident,
+                format!(
+                    "unknown backend `{}`, expected one of: postgres, mysql, sqlite",
+                    other
+                )
+            ),
+        }
+    }
+
+    /// The diesel backend type and the raw value type diesel hands to `FromSql::from_sql`.
+    fn diesel_paths(self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        match self {
+            Backend::Postgres => (
+                quote! { ::diesel::pg::Pg },
+                quote! { ::diesel::pg::PgValue },
+            ),
+            Backend::Mysql => (
+                quote! { ::diesel::mysql::Mysql },
+                quote! { ::diesel::mysql::MysqlValue },
+            ),
+            Backend::Sqlite => (
+                quote! { ::diesel::sqlite::Sqlite },
+                quote! { ::diesel::sqlite::SqliteValue },
+            ),
+        }
+    }
+}
+
+/// A single argument of the `#[fromtosql(...)]` attribute: `key = Ident` (e.g.
+/// `sql_type = MyType`), `key = "literal"` (e.g. `postgres_type = "my_type"`),
+/// `key(value, value, ...)` (e.g. `backend(postgres, mysql)`), or a bare `key` (e.g.
+/// `postgres_type`).
+enum FromToSqlArg {
+    Flag(Ident),
+    KeyValue(Ident, Ident),
+    KeyValueStr(Ident, String),
+    KeyList(Ident, Vec<Ident>),
+}
+
+impl Parse for FromToSqlArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let list = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            Ok(FromToSqlArg::KeyList(key, list.into_iter().collect()))
+        } else if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            if input.peek(syn::LitStr) {
+                let value: syn::LitStr = input.parse()?;
+                Ok(FromToSqlArg::KeyValueStr(key, value.value()))
+            } else {
+                let value: Ident = input.parse()?;
+                Ok(FromToSqlArg::KeyValue(key, value))
+            }
+        } else {
+            Ok(FromToSqlArg::Flag(key))
+        }
+    }
+}
+
+/// The built-in `value_style` renaming strategies for the string-based (non-`repr`) modes.
+///
+/// When a `value_style` is given, `FromToSql` computes every variant's on-the-wire string at
+/// macro-expansion time instead of delegating to `strum`'s `Display`/`FromStr`.
+#[derive(Clone, Copy)]
+enum ValueStyle {
+    Verbatim,
+    SnakeCase,
+    ScreamingSnake,
+    Uppercase,
+    Lowercase,
+    CamelCase,
+}
+
+impl ValueStyle {
+    fn parse(ident: &Ident) -> ValueStyle {
+        match ident.to_string().as_str() {
+            "verbatim" => ValueStyle::Verbatim,
+            "snake_case" => ValueStyle::SnakeCase,
+            "SCREAMING_SNAKE" => ValueStyle::ScreamingSnake,
+            "UPPERCASE" => ValueStyle::Uppercase,
+            "lowercase" => ValueStyle::Lowercase,
+            "camelCase" => ValueStyle::CamelCase,
+            other => abort!(
+                ident,
+                format!(
+                    "unknown value_style `{}`, expected one of: verbatim, snake_case, SCREAMING_SNAKE, UPPERCASE, lowercase, camelCase",
+                    other
+                )
+            ),
+        }
+    }
+
+    fn apply(self, variant_name: &str) -> String {
+        match self {
+            ValueStyle::Verbatim => variant_name.to_string(),
+            ValueStyle::SnakeCase => to_snake_case(variant_name),
+            ValueStyle::ScreamingSnake => to_snake_case(variant_name).to_uppercase(),
+            ValueStyle::Uppercase => variant_name.to_uppercase(),
+            ValueStyle::Lowercase => variant_name.to_lowercase(),
+            ValueStyle::CamelCase => {
+                let mut chars = variant_name.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+/// Collects every `#[fromtosql(...)]` argument found on an enum or a single variant.
+fn parse_fromtosql_args(attrs: &[syn::Attribute]) -> Vec<FromToSqlArg> {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("fromtosql"))
+        .flat_map(|a| {
+            a.parse_args_with(Punctuated::<FromToSqlArg, Token![,]>::parse_terminated)
+                .unwrap_or_else(|err| abort!(err.span(), "{}", err))
+        })
+        .collect()
+}
+
+/// Builds the error value tokens a generated `from_sql` returns when it reads back a value
+/// that doesn't match any variant, naming the offending value and every known variant.
+/// `found_expr` is the in-scope expression holding the decoded value (e.g. `value` or
+/// `other`); `variant_names` is the pre-joined, comma-separated list shown as the expected
+/// values: the computed DB strings in `value_style`/`rename` mode, the variant identifiers
+/// otherwise.
+/// Callers wrap the result in `Err(...)` themselves, since some call sites need the bare
+/// error value (`map_err`) and others need a `Result` (a `match` arm).
+fn unrecognized_value_error(
+    found_expr: proc_macro2::TokenStream,
+    variant_names: &str,
+) -> proc_macro2::TokenStream {
+    quote! {
+        format!(
+            "unrecognized enum value {:?}; expected one of [{}]",
+            #found_expr, #variant_names
+        )
+        .into()
+    }
+}
+
+/// Converts a `PascalCase`/`camelCase` identifier into `snake_case`, used to derive the
+/// default DB-side type name from the enum's Rust identifier.
+fn to_snake_case(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len());
+    for (i, ch) in ident.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
 
 /// This procedural Macro generates diesel FromSql and ToSql Instances for Enum
 ///
@@ -46,6 +227,90 @@ use syn::ItemEnum;
 /// [`MyEntityEnum`] should either implement [`FromStr`] or [`EnumString`] which is better if you
 /// can.
 ///
+/// By default the generated impls only target Postgres. Add a `backend(...)` argument to
+/// `#[fromtosql(...)]` to also generate `ToSql`/`FromSql` for MySQL and/or SQLite, e.g.
+/// `#[fromtosql(sql_type = MyEntityEnumSqlType, backend(postgres, mysql, sqlite))]`.
+///
+/// Writing the `MyEntityEnumSqlType` module by hand is only needed when it already exists
+/// elsewhere (e.g. it was generated by `diesel print-schema`). If there is no pre-existing
+/// type, add a `postgres_type = "..."` (or `diesel_type = "..."`) argument and `FromToSql`
+/// generates the whole companion type for you: the `SqlType` marker struct named by
+/// `sql_type`, tagged with the right `#[diesel(..._type(name = ...))]` attribute for every
+/// backend in `backend(...)`, plus the `AsExpression<MyEntityEnumSqlType>` /
+/// `AsExpression<Nullable<MyEntityEnumSqlType>>` impls for both `MyEntityEnum` and
+/// `&MyEntityEnum` that diesel needs to use it in inserts and queries, and the `Queryable`
+/// impl that gives `MyEntityEnum` its `FromSqlRow`. The DB type name defaults to the
+/// `snake_case` of the enum identifier when `postgres_type`/`diesel_type` is given as a bare
+/// flag without a value, and an optional `schema = "..."` sets the Postgres schema:
+///
+/// ```no_run
+/// # use diesel::deserialize::FromSql;
+/// # use diesel_sqltype_enum_pg::FromToSql;
+/// # use strum_macros::{Display, EnumString};
+/// #[derive(Debug, PartialEq, EnumString, Display, FromToSql)]
+/// #[fromtosql(sql_type = MyEntityEnumSqlType, postgres_type = "my_entity_enum", schema = "myschema")]
+/// enum MyEntityEnum {
+///     #[strum(serialize = "ONE")]
+///     EnumOne,
+///     #[strum(serialize = "TWO")]
+///     EnumTwo,
+/// }
+/// ```
+///
+/// Combine it with `backend(...)` to generate the companion type for MySQL/SQLite too. Both
+/// of those always come out as `#[diesel(mysql_type(name = "String"))]` /
+/// `#[diesel(sqlite_type(name = "Text"))]`, since diesel expects one of its fixed
+/// `MysqlType`/`SqliteType` wire variants there rather than a user-chosen type name:
+///
+/// ```no_run
+/// # use diesel::deserialize::FromSql;
+/// # use diesel_sqltype_enum_pg::FromToSql;
+/// # use strum_macros::{Display, EnumString};
+/// #[derive(Debug, PartialEq, EnumString, Display, FromToSql)]
+/// #[fromtosql(sql_type = MyEntityEnumSqlType, postgres_type = "my_entity_enum", backend(postgres, mysql, sqlite))]
+/// enum MyEntityEnum {
+///     #[strum(serialize = "ONE")]
+///     EnumOne,
+///     #[strum(serialize = "TWO")]
+///     EnumTwo,
+/// }
+/// ```
+///
+/// When the DB column stores a plain integer code instead of a named enum type, use
+/// `#[fromtosql(repr = Integer)]` (or `repr = SmallInt`) in place of `sql_type`/`backend`.
+/// Every variant must then carry an explicit discriminant, which becomes the value written
+/// and read from the column — no `strum` `Display`/`FromStr` is required for this mode:
+///
+/// ```no_run
+/// # use diesel::deserialize::FromSql;
+/// # use diesel_sqltype_enum_pg::FromToSql;
+/// #[derive(Debug, PartialEq, FromToSql)]
+/// #[fromtosql(repr = Integer)]
+/// enum MyEntityEnum {
+///     EnumOne = 1,
+///     EnumTwo = 2,
+/// }
+/// ```
+///
+/// `strum`'s `Display`/`FromStr` is also no longer required for the string-based modes: add a
+/// `value_style` argument (`verbatim`, `snake_case`, `SCREAMING_SNAKE`, `UPPERCASE`,
+/// `lowercase` or `camelCase`) and `FromToSql` computes every variant's DB string itself. A
+/// per-variant `#[fromtosql(rename = "...")]` overrides the computed string for legacy
+/// labels that don't follow the chosen style:
+///
+/// ```no_run
+/// # use diesel::deserialize::FromSql;
+/// # use diesel_sqltype_enum_pg::FromToSql;
+/// # struct MyEntityEnumSqlType;
+/// #[derive(Debug, PartialEq, FromToSql)]
+/// #[fromtosql(sql_type = MyEntityEnumSqlType, value_style = SCREAMING_SNAKE)]
+/// enum MyEntityEnum {
+///     EnumOne,
+///     #[fromtosql(rename = "ANTHOLOGY")]
+///     EnumTwo,
+/// }
+/// ```
+///
 #[proc_macro_error]
 #[proc_macro_derive(FromToSql, attributes(fromtosql))]
 pub fn describe(input: TokenStream) -> TokenStream {
@@ -56,50 +321,398 @@ pub fn describe(input: TokenStream) -> TokenStream {
 
     let ident = enum_typ.ident.clone();
 
-    let binding = enum_typ
-        .attrs
+    let args = parse_fromtosql_args(&enum_typ.attrs);
+
+    let repr = args.iter().find_map(|arg| match arg {
+        FromToSqlArg::KeyValue(key, value) if key == "repr" => Some(value.clone()),
+        _ => None,
+    });
+
+    if let Some(repr) = repr {
+        return generate_integer_from_to_sql(repr, enum_typ, ident);
+    }
+
+    let sql_type = args.iter().find_map(|arg| match arg {
+        FromToSqlArg::KeyValue(key, value) if key == "sql_type" => Some(value.clone()),
+        _ => None,
+    });
+
+    let sql_type = match sql_type {
+        Some(sql_type) => sql_type,
+        None => abort!(enum_typ, error_message()),
+    };
+
+    let backends = args
         .iter()
-        .filter(|a| a.path().is_ident("fromtosql"))
-        .flat_map(|a| {
-            let mut p = Vec::new();
-            let parser = a
-                .parse_args_with(Punctuated::<syn::Ident, Eq>::parse_separated_nonempty)
-                .unwrap();
-            let ident = parser.first().cloned().map(|f| f.to_string());
-            let value = parser.last().cloned();
-            if let Some("sql_type") = ident.as_deref() {
-                if let Some(value) = value {
-                    p.push(value)
-                }
+        .find_map(|arg| match arg {
+            FromToSqlArg::KeyList(key, backends) if key == "backend" => {
+                Some(backends.iter().map(Backend::parse).collect::<Vec<_>>())
             }
-            p
+            _ => None,
+        })
+        .unwrap_or_else(|| vec![Backend::Postgres]);
+
+    let db_type_name = args.iter().find_map(|arg| match arg {
+        FromToSqlArg::KeyValueStr(key, value) if key == "postgres_type" || key == "diesel_type" => {
+            Some(value.clone())
+        }
+        FromToSqlArg::Flag(key) if key == "postgres_type" || key == "diesel_type" => {
+            Some(String::new())
+        }
+        _ => None,
+    });
+
+    let schema = args.iter().find_map(|arg| match arg {
+        FromToSqlArg::KeyValueStr(key, value) if key == "schema" => Some(value.clone()),
+        _ => None,
+    });
+
+    let value_style = args.iter().find_map(|arg| match arg {
+        FromToSqlArg::KeyValue(key, value) if key == "value_style" => {
+            Some(ValueStyle::parse(value))
+        }
+        _ => None,
+    });
+
+    let renames = enum_typ
+        .variants
+        .iter()
+        .map(|variant| {
+            parse_fromtosql_args(&variant.attrs)
+                .iter()
+                .find_map(|arg| match arg {
+                    FromToSqlArg::KeyValueStr(key, value) if key == "rename" => Some(value.clone()),
+                    _ => None,
+                })
         })
         .collect::<Vec<_>>();
 
-    let att = match binding.first() {
-        Some(idnt) => idnt,
-        None => abort!(enum_typ, error_message()),
+    let from_to_sql = if value_style.is_some() || renames.iter().any(Option::is_some) {
+        let style = value_style.unwrap_or(ValueStyle::Verbatim);
+        let variant_values = enum_typ
+            .variants
+            .iter()
+            .zip(renames)
+            .map(|(variant, rename)| {
+                let db_value = rename.unwrap_or_else(|| style.apply(&variant.ident.to_string()));
+                (variant.ident.clone(), db_value)
+            })
+            .collect::<Vec<_>>();
+        abort_on_duplicate_values(&variant_values);
+        generate_owned_from_to_sql(
+            sql_type.clone(),
+            ident.clone(),
+            backends.clone(),
+            variant_values,
+        )
+    } else {
+        let variant_idents = enum_typ
+            .variants
+            .iter()
+            .map(|variant| variant.ident.clone())
+            .collect::<Vec<_>>();
+        generate_from_to_sql(
+            sql_type.clone(),
+            ident.clone(),
+            backends.clone(),
+            &variant_idents,
+        )
+    };
+
+    match db_type_name {
+        Some(db_type_name) => {
+            let sql_type_decl = generate_sql_type(sql_type, ident, db_type_name, schema, backends);
+            let mut output = proc_macro2::TokenStream::from(from_to_sql);
+            output.extend(proc_macro2::TokenStream::from(sql_type_decl));
+            output.into()
+        }
+        None => from_to_sql,
+    }
+}
+
+/// Generates the companion `SqlType` marker struct and the `AsExpression` impls a user would
+/// otherwise have to hand-write alongside `#[fromtosql(sql_type = ...)]`.
+fn generate_sql_type(
+    sql_type: Ident,
+    ident: Ident,
+    db_type_name: String,
+    schema: Option<String>,
+    backends: Vec<Backend>,
+) -> TokenStream {
+    let db_type_name = if db_type_name.is_empty() {
+        to_snake_case(&ident.to_string())
+    } else {
+        db_type_name
+    };
+
+    let backend_attrs = backends.iter().map(|backend| match backend {
+        Backend::Postgres => match &schema {
+            Some(schema) => {
+                quote! { #[diesel(postgres_type(name = #db_type_name, schema = #schema))] }
+            }
+            None => quote! { #[diesel(postgres_type(name = #db_type_name))] },
+        },
+        // Unlike Postgres' free-form custom type name, `mysql_type`/`sqlite_type` take a
+        // fixed `MysqlType`/`SqliteType` wire-representation variant, not a user-chosen DB
+        // type identifier, since both backends store the enum as plain string column data.
+        Backend::Mysql => quote! { #[diesel(mysql_type(name = "String"))] },
+        Backend::Sqlite => quote! { #[diesel(sqlite_type(name = "Text"))] },
+    });
+
+    let output = quote! {
+        #[derive(::diesel::sql_types::SqlType, ::diesel::query_builder::QueryId)]
+        #(#backend_attrs)*
+        pub struct #sql_type;
+
+        impl ::diesel::expression::AsExpression<#sql_type> for #ident {
+            type Expression = ::diesel::internal::derives::as_expression::Bound<#sql_type, Self>;
+            fn as_expression(self) -> Self::Expression {
+                ::diesel::internal::derives::as_expression::Bound::new(self)
+            }
+        }
+
+        impl ::diesel::expression::AsExpression<::diesel::sql_types::Nullable<#sql_type>> for #ident {
+            type Expression =
+                ::diesel::internal::derives::as_expression::Bound<::diesel::sql_types::Nullable<#sql_type>, Self>;
+            fn as_expression(self) -> Self::Expression {
+                ::diesel::internal::derives::as_expression::Bound::new(self)
+            }
+        }
+
+        impl<'expr> ::diesel::expression::AsExpression<#sql_type> for &'expr #ident {
+            type Expression = ::diesel::internal::derives::as_expression::Bound<#sql_type, Self>;
+            fn as_expression(self) -> Self::Expression {
+                ::diesel::internal::derives::as_expression::Bound::new(self)
+            }
+        }
+
+        impl<'expr> ::diesel::expression::AsExpression<::diesel::sql_types::Nullable<#sql_type>> for &'expr #ident {
+            type Expression =
+                ::diesel::internal::derives::as_expression::Bound<::diesel::sql_types::Nullable<#sql_type>, Self>;
+            fn as_expression(self) -> Self::Expression {
+                ::diesel::internal::derives::as_expression::Bound::new(self)
+            }
+        }
+
+        impl<__ST, __DB> ::diesel::deserialize::Queryable<__ST, __DB> for #ident
+        where
+            __DB: ::diesel::backend::Backend,
+            __ST: ::diesel::sql_types::SingleValue,
+            Self: ::diesel::deserialize::FromSql<__ST, __DB>,
+        {
+            type Row = Self;
+            fn build(row: Self) -> ::diesel::deserialize::Result<Self> {
+                Ok(row)
+            }
+        }
+    };
+    output.into()
+}
+
+fn generate_from_to_sql(
+    att: Ident,
+    ident: Ident,
+    backends: Vec<Backend>,
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let variant_names = variant_idents
+        .iter()
+        .map(|variant| variant.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let impls = backends.into_iter().map(|backend| {
+        let (backend_ty, raw_value_ty) = backend.diesel_paths();
+        let error_expr = unrecognized_value_error(quote! { value }, &variant_names);
+        // The SQLite `Output` takes bind values rather than raw bytes.
+        let write_value = match backend {
+            Backend::Sqlite => quote! { out.set_value(self.to_string()); },
+            _ => quote! {
+                use ::std::io::Write;
+                out.write_all(self.to_string().as_bytes())?;
+            },
+        };
+        quote! {
+            impl ::diesel::serialize::ToSql<#att, #backend_ty> for #ident {
+                fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, #backend_ty>) -> ::diesel::serialize::Result {
+                    #write_value
+                    Ok(::diesel::serialize::IsNull::No)
+                }
+            }
+
+            impl FromSql<#att, #backend_ty> for #ident {
+                fn from_sql(bytes: #raw_value_ty) -> ::diesel::deserialize::Result<Self> {
+                    use ::std::str::FromStr;
+                    let value: String = <String as FromSql<::diesel::sql_types::Text, #backend_ty>>::from_sql(bytes)?;
+                    #ident::from_str(value.as_str()).map_err(|_| #error_expr)
+                }
+            }
+        }
+    });
+
+    let output = quote! {
+        #(#impls)*
     };
+    output.into()
+}
+
+/// Aborts with a compile error naming the colliding variants when two variants render to the
+/// same DB string (via `value_style` and/or `rename`), since the generated `from_sql` match
+/// would otherwise silently make the later variant unreachable.
+fn abort_on_duplicate_values(variant_values: &[(Ident, String)]) {
+    for (i, (variant, value)) in variant_values.iter().enumerate() {
+        if let Some((other, _)) = variant_values[..i]
+            .iter()
+            .find(|(_, other_value)| other_value == value)
+        {
+            abort!(
+                variant,
+                format!(
+                    "`{}` and `{}` both resolve to the DB value {:?}; use `#[fromtosql(rename = \"...\")]` to disambiguate",
+                    other, variant, value
+                )
+            );
+        }
+    }
+}
+
+/// Generates `ToSql`/`FromSql` the same way as [`generate_from_to_sql`], except the DB
+/// string for each variant is computed at macro-expansion time (via `value_style`/`rename`)
+/// instead of being delegated to `strum`'s `Display`/`FromStr`.
+fn generate_owned_from_to_sql(
+    att: Ident,
+    ident: Ident,
+    backends: Vec<Backend>,
+    variant_values: Vec<(Ident, String)>,
+) -> TokenStream {
+    let variant_names = variant_values
+        .iter()
+        .map(|(_, value)| value.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let impls = backends.into_iter().map(|backend| {
+        let (backend_ty, raw_value_ty) = backend.diesel_paths();
+        // The SQLite `Output` takes bind values rather than raw bytes.
+        let to_sql_arms = variant_values.iter().map(|(variant, value)| match backend {
+            Backend::Sqlite => quote! { #ident::#variant => out.set_value(#value) },
+            _ => quote! { #ident::#variant => out.write_all(#value.as_bytes())? },
+        });
+        let write_import = match backend {
+            Backend::Sqlite => quote! {},
+            _ => quote! { use ::std::io::Write; },
+        };
+        let from_sql_arms = variant_values.iter().map(|(variant, value)| {
+            quote! { #value => Ok(#ident::#variant) }
+        });
+        let error_expr = unrecognized_value_error(quote! { found }, &variant_names);
+
+        quote! {
+            impl ::diesel::serialize::ToSql<#att, #backend_ty> for #ident {
+                fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, #backend_ty>) -> ::diesel::serialize::Result {
+                    #write_import
+                    match self {
+                        #(#to_sql_arms,)*
+                    };
+                    Ok(::diesel::serialize::IsNull::No)
+                }
+            }
+
+            impl FromSql<#att, #backend_ty> for #ident {
+                fn from_sql(bytes: #raw_value_ty) -> ::diesel::deserialize::Result<Self> {
+                    let value: String = <String as FromSql<::diesel::sql_types::Text, #backend_ty>>::from_sql(bytes)?;
+                    match value.as_str() {
+                        #(#from_sql_arms,)*
+                        found => Err(#error_expr),
+                    }
+                }
+            }
+        }
+    });
 
-    generate_from_to_sql(att.clone(), ident.clone())
+    let output = quote! {
+        #(#impls)*
+    };
+    output.into()
 }
 
-fn generate_from_to_sql(att: Ident, ident: Ident) -> TokenStream {
+/// Generates `ToSql`/`FromSql` for `#[fromtosql(repr = Integer)]` / `#[fromtosql(repr =
+/// SmallInt)]`, where the enum is stored as its explicit discriminant rather than as a
+/// DB-side named enum type.
+fn generate_integer_from_to_sql(repr: Ident, enum_typ: ItemEnum, ident: Ident) -> TokenStream {
+    let (sql_ty, int_ty) = match repr.to_string().as_str() {
+        "Integer" => (quote! { ::diesel::sql_types::Integer }, quote! { i32 }),
+        "SmallInt" => (quote! { ::diesel::sql_types::SmallInt }, quote! { i16 }),
+        other => abort!(
+            repr,
+            format!(
+                "unknown repr `{}`, expected one of: Integer, SmallInt",
+                other
+            )
+        ),
+    };
+
+    let variants = &enum_typ.variants;
+    if variants.is_empty() {
+        abort!(enum_typ, "`repr` mode requires at least one variant");
+    }
+
+    let discriminants = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        match &variant.discriminant {
+            Some((_, expr)) => expr,
+            None => abort!(
+                variant,
+                format!(
+                    "`#[fromtosql(repr = {})]` requires every variant to have an explicit discriminant, e.g. `{} = 1`",
+                    repr, variant_ident
+                )
+            ),
+        }
+    }).collect::<Vec<_>>();
+
+    let from_sql_arms = variants
+        .iter()
+        .zip(&discriminants)
+        .map(|(variant, discriminant)| {
+            let variant_ident = &variant.ident;
+            quote! { #discriminant => Ok(#ident::#variant_ident) }
+        });
+
+    // Matching through `&self` binds nothing, so this works for non-`Copy` enums too, unlike
+    // casting the dereferenced place (`*self as #int_ty`) which would move out of `&Self`.
+    let to_sql_arms = variants
+        .iter()
+        .zip(&discriminants)
+        .map(|(variant, discriminant)| {
+            let variant_ident = &variant.ident;
+            quote! { #ident::#variant_ident => #discriminant }
+        });
+
+    let variant_names = variants
+        .iter()
+        .map(|variant| variant.ident.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let error_expr = unrecognized_value_error(quote! { other }, &variant_names);
+
     let output = quote! {
-         impl ::diesel::serialize::ToSql<#att, ::diesel::pg::Pg> for #ident {
-             fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>) -> ::diesel::serialize::Result {
-                 use ::std::io::Write;
-                 out.write_all(self.to_string().as_bytes())?;
-                 Ok(::diesel::serialize::IsNull::No)
-             }
-         }
-
-         impl FromSql<#att, ::diesel::pg::Pg> for #ident {
+        impl ::diesel::serialize::ToSql<#sql_ty, ::diesel::pg::Pg> for #ident {
+            fn to_sql<'b>(&'b self, out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>) -> ::diesel::serialize::Result {
+                let value: #int_ty = match self {
+                    #(#to_sql_arms,)*
+                };
+                <#int_ty as ::diesel::serialize::ToSql<#sql_ty, ::diesel::pg::Pg>>::to_sql(&value, &mut out.reborrow())
+            }
+        }
+
+        impl FromSql<#sql_ty, ::diesel::pg::Pg> for #ident {
             fn from_sql(bytes: ::diesel::pg::PgValue) -> ::diesel::deserialize::Result<Self> {
-                use ::std::str::FromStr;
-                let value: String = <String as FromSql<::diesel::sql_types::Text, ::diesel::pg::Pg>>::from_sql(bytes)?;
-                #ident::from_str(value.as_str())
-                    .map_err(|e| format!("Error converting from PgValue {:?}", e).into())
+                let value = <#int_ty as FromSql<#sql_ty, ::diesel::pg::Pg>>::from_sql(bytes)?;
+                match value {
+                    #(#from_sql_arms,)*
+                    other => Err(#error_expr),
+                }
             }
         }
     };
@@ -119,3 +732,60 @@ fn error_message() -> &'static str {
     \t  EnumTwo,\n \
     } "
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unrecognized_value_error_names_the_found_value_and_known_variants() {
+        let tokens = unrecognized_value_error(quote! { value }, "ONE, TWO");
+        assert_eq!(
+            tokens.to_string(),
+            quote! {
+                format!("unrecognized enum value {:?}; expected one of [{}]", value, "ONE, TWO").into()
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn snake_case_converts_pascal_case() {
+        assert_eq!(to_snake_case("MyEntityEnum"), "my_entity_enum");
+    }
+
+    #[test]
+    fn snake_case_leaves_existing_snake_case_alone() {
+        assert_eq!(to_snake_case("already_snake"), "already_snake");
+    }
+
+    #[test]
+    fn value_style_verbatim_keeps_the_variant_name() {
+        assert_eq!(ValueStyle::Verbatim.apply("EnumOne"), "EnumOne");
+    }
+
+    #[test]
+    fn value_style_snake_case_converts_pascal_case() {
+        assert_eq!(ValueStyle::SnakeCase.apply("EnumOne"), "enum_one");
+    }
+
+    #[test]
+    fn value_style_screaming_snake_upcases_every_word() {
+        assert_eq!(ValueStyle::ScreamingSnake.apply("EnumOne"), "ENUM_ONE");
+    }
+
+    #[test]
+    fn value_style_uppercase_has_no_separators() {
+        assert_eq!(ValueStyle::Uppercase.apply("EnumOne"), "ENUMONE");
+    }
+
+    #[test]
+    fn value_style_lowercase_has_no_separators() {
+        assert_eq!(ValueStyle::Lowercase.apply("EnumOne"), "enumone");
+    }
+
+    #[test]
+    fn value_style_camel_case_lowercases_only_the_first_letter() {
+        assert_eq!(ValueStyle::CamelCase.apply("EnumOne"), "enumOne");
+    }
+}
@@ -13,6 +13,43 @@ enum EnumStruct {
     EnumTwo,
 }
 
+struct BackendEnumSqlType;
+
+#[derive(Debug, PartialEq, EnumString, Display, FromToSql)]
+#[fromtosql(sql_type = BackendEnumSqlType, backend(postgres, mysql, sqlite))]
+enum BackendEnum {
+    EnumOne,
+    EnumTwo,
+}
+
+#[derive(Debug, PartialEq, EnumString, Display, FromToSql)]
+#[fromtosql(
+    sql_type = MultiBackendEnumSqlType,
+    postgres_type = "multi_backend_enum",
+    backend(postgres, mysql, sqlite)
+)]
+enum MultiBackendEnum {
+    EnumOne,
+    EnumTwo,
+}
+
+#[derive(Debug, PartialEq, FromToSql)]
+#[fromtosql(repr = Integer)]
+enum IntegerEnum {
+    EnumOne = 1,
+    EnumTwo = 2,
+}
+
+struct ValueStyleEnumSqlType;
+
+#[derive(Debug, PartialEq, FromToSql)]
+#[fromtosql(sql_type = ValueStyleEnumSqlType, value_style = SCREAMING_SNAKE)]
+enum ValueStyleEnum {
+    EnumOne,
+    #[fromtosql(rename = "ANTHOLOGY")]
+    EnumTwo,
+}
+
 #[test]
 fn test_truth() {
     assert!(true);